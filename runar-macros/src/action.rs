@@ -0,0 +1,450 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::Parser;
+use syn::{parse_macro_input, ImplItemMethod, Meta, PathSegment, Type, TypePath};
+
+/// Marks a method as a service operation - see the crate-level docs for how
+/// to actually use this attribute.
+///
+/// `#[action]` cannot register its handler by itself: the registration is an
+/// `inventory::submit!`, which expands to an anonymous `const _: () = { ... };`
+/// that is only legal at module (or block) scope, never as a sibling of a
+/// method inside an `impl` block. So this attribute only works when the
+/// enclosing `impl` block is itself annotated with `#[runar_macros::service]`,
+/// which sees the whole block before this attribute would otherwise run,
+/// strips it, and emits the real registration outside the `impl`. Used on
+/// its own (without `#[service]` on the `impl`), it's a compile error rather
+/// than silently compiling into a never-registered handler.
+///
+/// # Parameters
+/// - `name`: The operation name that will be used in node.request() calls (default: method name)
+/// - `path`: An optional REST-like pattern (e.g. `"users/{id}/posts/{post_id}"`)
+///   that additionally addresses this action by dynamic segments, which are
+///   captured into `params` under their `{name}`. A trailing `{name:*}`
+///   segment captures the remainder of the operation string.
+/// - `alias`: May be repeated to register the same handler under additional
+///   operation names, e.g. `#[action(name = "get_user", alias = "fetch_user")]`.
+///   Duplicate names (including a repeated alias) are a compile error.
+/// - `guard`: May be repeated to name a method (`async fn(&self, context: &Context,
+///   params: &ParamsMap) -> Result<(), anyhow::Error>`) run, in declaration order,
+///   before parameter extraction. The first guard to return an error short-circuits
+///   the call with that error.
+///
+/// An unrecognized key, a non-string value, or a bare literal attribute
+/// (e.g. `#[action("users")]`) is a compile error at the attribute itself,
+/// rather than silently falling back to the method's default name.
+///
+/// # Examples
+/// ```rust
+/// #[runar_macros::service]
+/// impl UserService {
+///     // Typed parameters are extracted from `params` automatically
+///     #[action(name = "get_user")]
+///     async fn get_user(&self, ctx: &Context, id: u64, include_posts: bool) -> Result<User> {
+///         // Implementation
+///     }
+///
+///     // Default name from method
+///     #[action]
+///     async fn get_posts(&self, ctx: &Context) -> Result<Vec<Post>> {
+///         // Implementation
+///     }
+/// }
+/// ```
+///
+/// # Parameter Handling
+/// Every argument after `&self` and the context argument (named `context`,
+/// `ctx`, `_context` or `_ctx`) is treated as an action parameter. The macro
+/// extracts each one from the incoming `params` map by name via
+/// `crate::utils::extract_parameter::<T, _>`, so the type itself must be
+/// deserializable; if it isn't, the generated call site fails to compile.
+///
+/// # Return Values
+/// - Action methods may return `Result<ServiceResponse>` directly, or any
+///   other `Result<T>` where `T` is convertible to `ValueType` - the latter
+///   gets wrapped in `ServiceResponse::success` automatically.
+/// - Error handling is done via the `?` operator in the generated code.
+pub fn action(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let method = parse_macro_input!(item as ImplItemMethod);
+    let error = syn::Error::new_spanned(
+        &method.sig,
+        "#[action] must be used inside an impl block annotated with #[runar_macros::service]; \
+         inventory::submit! can't run spliced next to a method, only at module scope",
+    )
+    .to_compile_error();
+
+    // Leave the method itself intact so the rest of the impl block keeps
+    // compiling and the caller sees this error alongside any others.
+    quote! {
+        #method
+        #error
+    }
+    .into()
+}
+
+/// Expands a single `#[action(...)]`-tagged method into its registration
+/// tokens, for splicing in as a sibling of the `impl` block by
+/// `crate::service::service`.
+///
+/// `attr` is the attribute's argument tokens (e.g. `name = "get_user"`), and
+/// `self_ty` is the concrete service type the method is implemented on -
+/// unlike when this logic ran as `#[action]` directly on the method, the
+/// registration here is emitted *outside* the `impl` block, where `Self`
+/// doesn't resolve.
+pub(crate) fn expand_action(
+    attr: proc_macro2::TokenStream,
+    method: &ImplItemMethod,
+    self_ty: &Type,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let method_name = &method.sig.ident;
+    let method_name_str = method_name.to_string();
+
+    // Parse the attribute tokens into a list of Meta items, if any. A
+    // malformed attribute (e.g. `#[action("users")]`) is a compile error
+    // rather than a silently-ignored attribute, so typos don't quietly fall
+    // back to the method's default name.
+    let meta_vec: Vec<Meta> = if attr.is_empty() {
+        Vec::new()
+    } else {
+        let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+        match parser.parse2(attr) {
+            Ok(meta_list) => meta_list.into_iter().collect(),
+            Err(err) => {
+                return Err(syn::Error::new(
+                    err.span(),
+                    format!(
+                        "invalid action definition, expected #[action(name = \"...\")]: {err}"
+                    ),
+                ))
+            }
+        }
+    };
+
+    crate::utils::validate_attribute_keys(&meta_vec, "action", &["name", "path", "alias", "guard"])?;
+
+    // Find the name attribute or default to method name
+    let operation_name = crate::utils::extract_name_value_pairs(&meta_vec)
+        .get("name")
+        .cloned()
+        .unwrap_or_else(|| method_name_str.clone());
+
+    // A `path = "users/{id}"` attribute registers a path-pattern alongside
+    // the flat operation name, so the action can also be addressed with
+    // dynamic segments captured into `params`.
+    let path_template_tokens = match crate::utils::find_name_value_lit(&meta_vec, "path") {
+        Some(lit) => {
+            let segments = crate::path_pattern::parse_path_pattern(lit)?;
+            let tokens = crate::path_pattern::path_template_tokens(&lit.value(), &segments);
+            quote! { Some(#tokens) }
+        }
+        None => quote! { None },
+    };
+
+    // Verify method is async
+    if method.sig.asyncness.is_none() {
+        return Err(syn::Error::new_spanned(
+            method.sig.fn_token,
+            "action methods must be async",
+        ));
+    }
+
+    // Action handlers must be methods with &self or &mut self
+    if !matches!(method.sig.inputs.first(), Some(syn::FnArg::Receiver(_))) {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            "Action handlers must be methods with &self or &mut self parameter",
+        ));
+    }
+
+    // Pull the user's typed parameters out of the signature (skipping
+    // `self` and the context argument) and build both the extraction
+    // statements and the argument list for the call, in signature order.
+    let parameters = extract_parameters(&method.sig.inputs)?;
+    let extraction_code = generate_parameter_extraction(&parameters);
+    // Owned so each `registered_names.iter().map(...)` iteration below can
+    // clone its own copy rather than moving the shared one out of an `FnMut`
+    // closure (which `#(#call_args),*`'s `IntoIterator::into_iter` would do).
+    let call_args: Vec<proc_macro2::TokenStream> = parameters
+        .iter()
+        .map(|param| param.call_expr.clone())
+        .collect();
+
+    // `guard = "require_admin"` names a method resolvable on the service
+    // (`async fn(&self, context: &Context, params: &ParamsMap) -> Result<(), anyhow::Error>`)
+    // that is run, in declaration order, before parameter extraction.
+    // `validate_attribute_keys` above already guarantees every `guard` entry
+    // is a string literal, but the literal's *value* still has to be checked:
+    // `syn::Ident::new` panics (rather than returning a `syn::Result`) on a
+    // string that isn't a valid identifier, so a guard like `"123bad"` must
+    // be rejected here before it ever reaches that call.
+    let guard_lits = crate::utils::find_name_value_lits(&meta_vec, "guard");
+    let mut guard_calls = Vec::with_capacity(guard_lits.len());
+    for guard_lit in guard_lits {
+        let guard_name = guard_lit.value();
+        let guard_ident = syn::parse_str::<syn::Ident>(&guard_name).map_err(|_| {
+            syn::Error::new(
+                guard_lit.span(),
+                format!("guard \"{guard_name}\" is not a valid method name"),
+            )
+        })?;
+        guard_calls.push(quote! {
+            service.#guard_ident(&context, &params)
+                .await
+                .context(format!("Guard \"{}\" rejected {}", #guard_name, #operation_name))?;
+        });
+    }
+    let guard_code = quote! { #(#guard_calls)* };
+
+    // Analyze the return type to determine if it's already ServiceResponse or needs wrapping
+    let returns_service_response = is_service_response_return(&method.sig.output);
+
+    // An action can be registered under several names: the primary `name`
+    // plus any `alias = "..."` entries, all pointing at the same handler
+    // body. Collect them up front so we can reject collisions before
+    // generating any code.
+    let mut registered_names: Vec<(String, proc_macro2::Span)> =
+        vec![(operation_name.clone(), method_name.span())];
+    for alias_lit in crate::utils::find_name_value_lits(&meta_vec, "alias") {
+        registered_names.push((alias_lit.value(), alias_lit.span()));
+    }
+
+    reject_duplicate_names(&registered_names)?;
+
+    // Generate one `inventory::submit!` per registered name, each pointing
+    // at its own copy of the handler closure so error messages report the
+    // name the caller actually used.
+    let registrations = registered_names.iter().map(|(name, _)| {
+        let call_args = call_args.clone();
+        let handler_code = if returns_service_response {
+            quote! {
+                #guard_code
+                #extraction_code
+
+                // Call the method with the extracted parameters
+                service.#method_name(#(#call_args),*).await
+                    .context(format!("Error executing {}", #name))
+            }
+        } else {
+            quote! {
+                #guard_code
+                #extraction_code
+
+                // Call the method to get the native return value
+                let result = service.#method_name(#(#call_args),*).await
+                    .context(format!("Error executing {}", #name))?;
+
+                // Wrap the result in a ServiceResponse
+                Ok(runar_node::ServiceResponse::success(
+                    "Operation succeeded",
+                    Some(runar_node::value::IntoValue::into_value(result)),
+                ))
+            }
+        };
+
+        quote! {
+            inventory::submit! {
+                crate::registry::ActionItem {
+                    name: #name.to_string(),
+                    service_type_id: std::any::TypeId::of::<#self_ty>(),
+                    path_template: #path_template_tokens,
+                    handler_fn: Box::new(move |service_ref, context, _operation, params| {
+                        Box::pin(async move {
+                            use anyhow::Context;
+
+                            // Downcast the service reference to our concrete type
+                            let service = service_ref.downcast_ref::<#self_ty>()
+                                .ok_or_else(|| anyhow::anyhow!("Service type mismatch in action handler"))?;
+
+                            #handler_code
+                        })
+                    }),
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #(#registrations)*
+    })
+}
+
+/// A parameter extracted from an action method's signature, along with the
+/// expression used to pass it (by name) into the original method call.
+struct Parameter {
+    is_context: bool,
+    name: String,
+    ty: Type,
+    call_expr: proc_macro2::TokenStream,
+}
+
+/// Extracts parameters from a function signature, skipping `self`.
+///
+/// The context argument (named `context`, `ctx`, `_context` or `_ctx`) is
+/// kept in the list so its position in the call is preserved, but it is
+/// marked so `generate_parameter_extraction` knows not to extract it from
+/// `params`.
+fn extract_parameters(
+    inputs: &syn::punctuated::Punctuated<syn::FnArg, syn::token::Comma>,
+) -> syn::Result<Vec<Parameter>> {
+    let mut parameters = Vec::new();
+
+    for input in inputs.iter().skip(1) {
+        let pat_type = match input {
+            syn::FnArg::Typed(pat_type) => pat_type,
+            syn::FnArg::Receiver(_) => {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "action methods must take a single &self or &mut self receiver",
+                ))
+            }
+        };
+
+        let pat_ident = match &*pat_type.pat {
+            syn::Pat::Ident(pat_ident) => pat_ident,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    pat_type,
+                    "action parameters must be simple identifiers",
+                ))
+            }
+        };
+
+        let name = pat_ident.ident.to_string();
+        let is_context = matches!(name.as_str(), "context" | "ctx" | "_context" | "_ctx");
+        let ident = &pat_ident.ident;
+
+        let call_expr = if is_context {
+            quote! { &context }
+        } else {
+            quote! { #ident }
+        };
+
+        parameters.push(Parameter {
+            is_context,
+            name,
+            ty: (*pat_type.ty).clone(),
+            call_expr,
+        });
+    }
+
+    Ok(parameters)
+}
+
+/// Generates the `let <param> = extract_parameter::<Ty, _>(...)?;` statements
+/// for every non-context parameter, in signature order.
+fn generate_parameter_extraction(parameters: &[Parameter]) -> proc_macro2::TokenStream {
+    let mut extraction_code = proc_macro2::TokenStream::new();
+
+    for param in parameters {
+        if param.is_context {
+            continue;
+        }
+
+        let ident = syn::Ident::new(&param.name, proc_macro2::Span::call_site());
+        let ty = &param.ty;
+        let param_name = &param.name;
+        let error_msg = format!("Missing required parameter: {}", param.name);
+
+        extraction_code.extend(quote! {
+            let #ident = crate::utils::extract_parameter::<#ty, _>(
+                &params,
+                #param_name,
+                #error_msg,
+            )?;
+        });
+    }
+
+    extraction_code
+}
+
+/// Check if the return type is Result<ServiceResponse>
+fn is_service_response_return(output: &syn::ReturnType) -> bool {
+    match output {
+        syn::ReturnType::Default => false,
+        syn::ReturnType::Type(_, ty) => match &**ty {
+            Type::Path(TypePath { path, .. }) => {
+                if is_type_named(path, "Result") {
+                    if let Some(PathSegment { arguments, .. }) = path.segments.last() {
+                        if let syn::PathArguments::AngleBracketed(args) = arguments {
+                            if let Some(syn::GenericArgument::Type(Type::Path(TypePath {
+                                path,
+                                ..
+                            }))) = args.args.first()
+                            {
+                                return is_type_named(path, "ServiceResponse");
+                            }
+                        }
+                    }
+                }
+                false
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Check if a type is named a certain way
+fn is_type_named(path: &syn::Path, name: &str) -> bool {
+    path.segments.iter().any(|segment| segment.ident == name)
+}
+
+/// Rejects a set of registered names (the primary `name` plus any `alias`
+/// entries) if any two are identical, pointing the error at the later of
+/// the two occurrences.
+fn reject_duplicate_names(names: &[(String, proc_macro2::Span)]) -> syn::Result<()> {
+    let mut seen = std::collections::HashSet::new();
+    for (name, span) in names {
+        if !seen.insert(name.clone()) {
+            return Err(syn::Error::new(
+                *span,
+                format!("duplicate action name \"{name}\" on the same handler"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span() -> proc_macro2::Span {
+        proc_macro2::Span::call_site()
+    }
+
+    #[test]
+    fn reject_duplicate_names_allows_distinct_names() {
+        let names = vec![
+            ("get_user".to_string(), span()),
+            ("fetch_user".to_string(), span()),
+            ("user".to_string(), span()),
+        ];
+        assert!(reject_duplicate_names(&names).is_ok());
+    }
+
+    #[test]
+    fn reject_duplicate_names_rejects_alias_matching_primary_name() {
+        let names = vec![
+            ("get_user".to_string(), span()),
+            ("get_user".to_string(), span()),
+        ];
+        let err = reject_duplicate_names(&names).unwrap_err();
+        assert!(err.to_string().contains("duplicate action name \"get_user\""));
+    }
+
+    #[test]
+    fn reject_duplicate_names_rejects_repeated_alias() {
+        let names = vec![
+            ("get_user".to_string(), span()),
+            ("fetch_user".to_string(), span()),
+            ("fetch_user".to_string(), span()),
+        ];
+        let err = reject_duplicate_names(&names).unwrap_err();
+        assert!(err.to_string().contains("duplicate action name \"fetch_user\""));
+    }
+
+    #[test]
+    fn reject_duplicate_names_empty_list_is_ok() {
+        assert!(reject_duplicate_names(&[]).is_ok());
+    }
+}
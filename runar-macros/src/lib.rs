@@ -0,0 +1,56 @@
+//! Procedural macros for defining Runar services.
+//!
+//! These attribute macros register handlers into a crate-local `registry`
+//! (via `inventory`) so the node dispatcher can look services up by name at
+//! runtime instead of every caller needing compile-time knowledge of every
+//! service. The pattern mirrors `actix-web-codegen`'s route attributes, with
+//! `params` standing in for the HTTP request and `Context` standing in for
+//! request-scoped state.
+//!
+//! A crate that uses these macros is expected to expose `registry` and
+//! `utils` modules at its own root (typically by re-exporting them from
+//! `runar-node`), since the generated code refers to them as `crate::registry`
+//! and `crate::utils`.
+//!
+//! `#[action]` and `#[subscribe]` only mark a method - the `impl` block they
+//! live in must itself be annotated with `#[service]`, which is what
+//! actually collects the tagged methods and emits their `inventory::submit!`
+//! registrations (an `inventory::submit!` can't be spliced next to a method
+//! inside an `impl` block; the docs on `service::service` in
+//! `src/service.rs` explain why).
+
+use proc_macro::TokenStream;
+
+mod action;
+mod path_pattern;
+mod service;
+mod subscribe;
+mod utils;
+
+/// Marks a method as a service operation invocable via `node.request("service/action", params)`.
+///
+/// See the docs on `action::action` in `src/action.rs` for the full parameter
+/// and return value handling rules.
+#[proc_macro_attribute]
+pub fn action(attr: TokenStream, item: TokenStream) -> TokenStream {
+    action::action(attr, item)
+}
+
+/// Marks a method as an event handler invoked whenever a matching event is published.
+///
+/// See the docs on `subscribe::subscribe` in `src/subscribe.rs` for the full
+/// topic and payload handling rules.
+#[proc_macro_attribute]
+pub fn subscribe(attr: TokenStream, item: TokenStream) -> TokenStream {
+    subscribe::subscribe(attr, item)
+}
+
+/// Marks an `impl` block as containing `#[action]`/`#[subscribe]` handlers.
+///
+/// See the docs on `service::service` in `src/service.rs` for why this is
+/// required alongside those two attributes, rather than each one registering
+/// itself.
+#[proc_macro_attribute]
+pub fn service(attr: TokenStream, item: TokenStream) -> TokenStream {
+    service::service(attr, item)
+}
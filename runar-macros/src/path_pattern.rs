@@ -0,0 +1,202 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
+use syn::LitStr;
+
+/// One segment of a parsed `#[action(path = "...")]` pattern.
+#[derive(Debug, Clone)]
+pub enum Segment {
+    Literal(String),
+    Capture(String),
+    Wildcard(String),
+}
+
+/// Parses an action path pattern into its segments at macro-expansion time.
+///
+/// Supports literal segments, `{name}` captures, and a trailing `{name:*}`
+/// wildcard that captures the remainder of the operation string. Duplicate
+/// capture names and empty segments are rejected with a spanned error so the
+/// mistake shows up at the attribute, not at dispatch time.
+pub fn parse_path_pattern(lit: &LitStr) -> syn::Result<Vec<Segment>> {
+    let pattern = lit.value();
+    let raw_segments: Vec<&str> = pattern.split('/').collect();
+    let last_index = raw_segments.len().saturating_sub(1);
+
+    let mut segments = Vec::with_capacity(raw_segments.len());
+    let mut seen_names = HashSet::new();
+
+    for (i, raw) in raw_segments.iter().enumerate() {
+        if raw.is_empty() {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!("action path \"{pattern}\" contains an empty segment"),
+            ));
+        }
+
+        let opens = raw.starts_with('{');
+        let closes = raw.ends_with('}');
+        // Only a segment that *opens* a capture but never closes it (e.g. a
+        // truncated "{id") is a mistake worth rejecting. A segment that merely
+        // ends in `}` without opening one (e.g. a literal like "v{1}") is not
+        // an attempted capture and must fall through to `Segment::Literal`
+        // below, same as it always has.
+        if opens && !closes {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "malformed capture segment \"{raw}\" in action path \"{pattern}\" (expected \"{{name}}\")"
+                ),
+            ));
+        }
+
+        let segment = if opens && closes {
+            let inner = &raw[1..raw.len() - 1];
+            match inner.strip_suffix(":*") {
+                Some(name) => {
+                    if name.is_empty() {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!("wildcard segment in action path \"{pattern}\" must have a name, e.g. \"{{rest:*}}\""),
+                        ));
+                    }
+                    if i != last_index {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "wildcard segment \"{{{name}:*}}\" must be the last segment in \"{pattern}\""
+                            ),
+                        ));
+                    }
+                    Segment::Wildcard(name.to_string())
+                }
+                None => {
+                    if inner.is_empty() {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!("capture segment in action path \"{pattern}\" must have a name, e.g. \"{{id}}\""),
+                        ));
+                    }
+                    Segment::Capture(inner.to_string())
+                }
+            }
+        } else {
+            Segment::Literal(raw.to_string())
+        };
+
+        let capture_name = match &segment {
+            Segment::Capture(name) | Segment::Wildcard(name) => Some(name),
+            Segment::Literal(_) => None,
+        };
+        if let Some(name) = capture_name {
+            if !seen_names.insert(name.clone()) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("duplicate capture name \"{name}\" in action path \"{pattern}\""),
+                ));
+            }
+        }
+
+        segments.push(segment);
+    }
+
+    Ok(segments)
+}
+
+/// Emits the tokens that construct the runtime `PathTemplate` for a parsed pattern.
+pub fn path_template_tokens(pattern: &str, segments: &[Segment]) -> TokenStream {
+    let segment_tokens = segments.iter().map(|segment| match segment {
+        Segment::Literal(s) => quote! { runar_node::path_template::PathSegment::Literal(#s.to_string()) },
+        Segment::Capture(s) => quote! { runar_node::path_template::PathSegment::Capture(#s.to_string()) },
+        Segment::Wildcard(s) => quote! { runar_node::path_template::PathSegment::Wildcard(#s.to_string()) },
+    });
+
+    quote! {
+        runar_node::path_template::PathTemplate::new(#pattern, vec![#(#segment_tokens),*])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit(pattern: &str) -> LitStr {
+        LitStr::new(pattern, proc_macro2::Span::call_site())
+    }
+
+    #[test]
+    fn parses_literal_and_capture_segments() {
+        let segments = parse_path_pattern(&lit("users/{id}/posts/{post_id}")).unwrap();
+        assert!(matches!(
+            segments.as_slice(),
+            [
+                Segment::Literal(a),
+                Segment::Capture(b),
+                Segment::Literal(c),
+                Segment::Capture(d),
+            ] if a == "users" && b == "id" && c == "posts" && d == "post_id"
+        ));
+    }
+
+    #[test]
+    fn parses_trailing_wildcard() {
+        let segments = parse_path_pattern(&lit("files/{rest:*}")).unwrap();
+        assert!(matches!(
+            segments.as_slice(),
+            [Segment::Literal(a), Segment::Wildcard(b)] if a == "files" && b == "rest"
+        ));
+    }
+
+    #[test]
+    fn rejects_empty_segment() {
+        let err = parse_path_pattern(&lit("users//posts")).unwrap_err();
+        assert!(err.to_string().contains("empty segment"));
+    }
+
+    #[test]
+    fn rejects_duplicate_capture_names() {
+        let err = parse_path_pattern(&lit("users/{id}/friends/{id}")).unwrap_err();
+        assert!(err.to_string().contains("duplicate capture name"));
+    }
+
+    #[test]
+    fn rejects_non_trailing_wildcard() {
+        let err = parse_path_pattern(&lit("files/{rest:*}/meta")).unwrap_err();
+        assert!(err.to_string().contains("must be the last segment"));
+    }
+
+    #[test]
+    fn rejects_unbalanced_braces_instead_of_treating_as_literal() {
+        // A segment like "{id" (missing the closing brace) must not be
+        // silently accepted as a literal segment - that would make the
+        // action effectively unreachable at dispatch time.
+        let err = parse_path_pattern(&lit("users/{id")).unwrap_err();
+        assert!(err.to_string().contains("malformed capture segment"));
+    }
+
+    #[test]
+    fn accepts_literal_segment_with_a_trailing_brace() {
+        // "v{1}" doesn't open a capture (it starts with "v"), so the stray
+        // trailing "}" must not trip the malformed-capture check - it's a
+        // plain literal, same as the pre-fix behavior.
+        let segments = parse_path_pattern(&lit("api/v{1}")).unwrap();
+        assert!(matches!(
+            segments.as_slice(),
+            [Segment::Literal(a), Segment::Literal(b)] if a == "api" && b == "v{1}"
+        ));
+    }
+
+    #[test]
+    fn rejects_empty_capture_name() {
+        let err = parse_path_pattern(&lit("users/{}")).unwrap_err();
+        assert!(err.to_string().contains("must have a name"));
+    }
+
+    #[test]
+    fn path_template_tokens_embed_parsed_segments() {
+        let segments = parse_path_pattern(&lit("users/{id}")).unwrap();
+        let tokens = path_template_tokens("users/{id}", &segments).to_string();
+        assert!(tokens.contains("PathTemplate :: new"));
+        assert!(tokens.contains("PathSegment :: Literal"));
+        assert!(tokens.contains("PathSegment :: Capture"));
+    }
+}
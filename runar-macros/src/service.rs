@@ -0,0 +1,101 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, ImplItem, ItemImpl};
+
+/// Marks an `impl` block as containing `#[action]`/`#[subscribe]` handlers.
+///
+/// `#[action]` and `#[subscribe]` register their handler via
+/// `inventory::submit!`, which expands to an anonymous `const _: () = { ... };`
+/// - legal at module (or block) scope, but not as a sibling of a method
+/// inside an `impl` block. So neither attribute can register its own
+/// handler directly; instead this macro sees the whole `impl` block
+/// (including its inner attributes, still unexpanded) before either of them
+/// would otherwise run, strips each tagged method's attribute, and emits
+/// each handler's registrations (one per action name or alias, one per
+/// subscription) as siblings of the - otherwise untouched -
+/// `impl` block, where `inventory::submit!` is legal and `Self` has been
+/// replaced with the concrete service type.
+///
+/// # Examples
+/// ```rust
+/// #[runar_macros::service]
+/// impl UserService {
+///     #[action(name = "get_user")]
+///     async fn get_user(&self, ctx: &Context, id: u64) -> Result<User> {
+///         // Implementation
+///     }
+///
+///     #[subscribe(topic = "user.created")]
+///     async fn on_user_created(&self, ctx: &Context, payload: ValueType) -> Result<(), anyhow::Error> {
+///         // Implementation
+///         Ok(())
+///     }
+/// }
+/// ```
+pub fn service(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut item_impl = parse_macro_input!(item as ItemImpl);
+    let self_ty = (*item_impl.self_ty).clone();
+
+    let mut registrations = Vec::new();
+
+    for impl_item in &mut item_impl.items {
+        let method = match impl_item {
+            ImplItem::Method(method) => method,
+            _ => continue,
+        };
+
+        let action_pos = method.attrs.iter().position(|attr| attr.path.is_ident("action"));
+        let subscribe_pos = method
+            .attrs
+            .iter()
+            .position(|attr| attr.path.is_ident("subscribe"));
+
+        match (action_pos, subscribe_pos) {
+            (Some(pos), None) => {
+                let attr = method.attrs.remove(pos);
+                let attr_tokens = attr_arg_tokens(&attr);
+                match crate::action::expand_action(attr_tokens, method, &self_ty) {
+                    Ok(tokens) => registrations.push(tokens),
+                    Err(err) => return err.to_compile_error().into(),
+                }
+            }
+            (None, Some(pos)) => {
+                let attr = method.attrs.remove(pos);
+                let attr_tokens = attr_arg_tokens(&attr);
+                match crate::subscribe::expand_subscribe(attr_tokens, method, &self_ty) {
+                    Ok(tokens) => registrations.push(tokens),
+                    Err(err) => return err.to_compile_error().into(),
+                }
+            }
+            (Some(_), Some(_)) => {
+                return syn::Error::new_spanned(
+                    &method.sig,
+                    "a method can't be both #[action] and #[subscribe]",
+                )
+                .to_compile_error()
+                .into();
+            }
+            (None, None) => {}
+        }
+    }
+
+    let output = quote! {
+        #item_impl
+
+        #(#registrations)*
+    };
+
+    TokenStream::from(output)
+}
+
+/// Returns the argument tokens inside an attribute's parentheses, e.g. the
+/// `name = "get_user"` in `#[action(name = "get_user")]`, or empty tokens
+/// for a bare `#[action]`. `syn::Attribute::tokens` includes the delimiter
+/// group itself, so this unwraps it the same way the old `attr: TokenStream`
+/// macro argument used to arrive already unwrapped.
+fn attr_arg_tokens(attr: &syn::Attribute) -> proc_macro2::TokenStream {
+    match attr.tokens.clone().into_iter().next() {
+        Some(proc_macro2::TokenTree::Group(group)) => group.stream(),
+        _ => proc_macro2::TokenStream::new(),
+    }
+}
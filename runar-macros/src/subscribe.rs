@@ -0,0 +1,145 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::Parser;
+use syn::{parse_macro_input, ImplItemMethod, Meta, Type};
+
+/// Marks a method as an event handler - see the crate-level docs for how to
+/// actually use this attribute.
+///
+/// Mirrors `#[action]`'s structure but registers a `SubscriptionItem`
+/// instead of an `ActionItem`: the handler is invoked whenever a matching
+/// event is published on its topic, rather than in response to a direct
+/// `node.request()` call.
+///
+/// Like `#[action]`, this attribute can't register its handler by itself -
+/// `inventory::submit!` expands to an anonymous `const _: () = { ... };`,
+/// which is only legal at module (or block) scope, not as a sibling of a
+/// method inside an `impl` block. So `#[subscribe]` only works when the
+/// enclosing `impl` block is annotated with `#[runar_macros::service]`,
+/// which strips this attribute and emits the real registration outside the
+/// `impl`. Used on its own, it's a compile error.
+///
+/// # Parameters
+/// - `topic`: The topic this handler listens on (default: method name)
+///
+/// # Examples
+/// ```rust
+/// #[runar_macros::service]
+/// impl UserService {
+///     #[subscribe(topic = "user.created")]
+///     async fn on_user_created(&self, ctx: &Context, payload: ValueType) -> Result<(), anyhow::Error> {
+///         // Implementation
+///         Ok(())
+///     }
+///
+///     // Default topic from method name
+///     #[subscribe]
+///     async fn user_deleted(&self, ctx: &Context, payload: ValueType) -> Result<(), anyhow::Error> {
+///         // Implementation
+///         Ok(())
+///     }
+/// }
+/// ```
+pub fn subscribe(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let method = parse_macro_input!(item as ImplItemMethod);
+    let error = syn::Error::new_spanned(
+        &method.sig,
+        "#[subscribe] must be used inside an impl block annotated with #[runar_macros::service]; \
+         inventory::submit! can't run spliced next to a method, only at module scope",
+    )
+    .to_compile_error();
+
+    // Leave the method itself intact so the rest of the impl block keeps
+    // compiling and the caller sees this error alongside any others.
+    quote! {
+        #method
+        #error
+    }
+    .into()
+}
+
+/// Expands a single `#[subscribe(...)]`-tagged method into its registration
+/// tokens, for splicing in as a sibling of the `impl` block by
+/// `crate::service::service`.
+///
+/// `attr` is the attribute's argument tokens (e.g. `topic = "user.created"`),
+/// and `self_ty` is the concrete service type the method is implemented on -
+/// unlike when this logic ran as `#[subscribe]` directly on the method, the
+/// registration here is emitted *outside* the `impl` block, where `Self`
+/// doesn't resolve.
+pub(crate) fn expand_subscribe(
+    attr: proc_macro2::TokenStream,
+    method: &ImplItemMethod,
+    self_ty: &Type,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let method_name = &method.sig.ident;
+    let method_name_str = method_name.to_string();
+
+    // Parse the attribute tokens into a list of Meta items, if any. A
+    // malformed attribute (e.g. `#[subscribe("user.created")]` or a typo'd
+    // key) is a compile error rather than a silently-ignored attribute, so
+    // typos don't quietly fall back to the method's default topic.
+    let meta_vec: Vec<Meta> = if attr.is_empty() {
+        Vec::new()
+    } else {
+        let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+        match parser.parse2(attr) {
+            Ok(meta_list) => meta_list.into_iter().collect(),
+            Err(err) => {
+                return Err(syn::Error::new(
+                    err.span(),
+                    format!(
+                        "invalid subscribe definition, expected #[subscribe(topic = \"...\")]: {err}"
+                    ),
+                ))
+            }
+        }
+    };
+
+    crate::utils::validate_attribute_keys(&meta_vec, "subscribe", &["topic"])?;
+
+    // Get the topic from attributes or use the method name, exactly like `action` does for `name`
+    let topic = crate::utils::extract_name_value_pairs(&meta_vec)
+        .get("topic")
+        .cloned()
+        .unwrap_or(method_name_str);
+
+    // Verify method is async
+    if method.sig.asyncness.is_none() {
+        return Err(syn::Error::new_spanned(
+            method.sig.fn_token,
+            "subscribe handlers must be async",
+        ));
+    }
+
+    // Subscribe handlers must be methods with &self or &mut self
+    if !matches!(method.sig.inputs.first(), Some(syn::FnArg::Receiver(_))) {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            "Subscribe handlers must be methods with &self or &mut self parameter",
+        ));
+    }
+
+    // Generate the registration
+    Ok(quote! {
+        inventory::submit! {
+            crate::registry::SubscriptionItem {
+                topic: #topic.to_string(),
+                service_type_id: std::any::TypeId::of::<#self_ty>(),
+                handler_fn: Box::new(move |service_ref, context, payload| {
+                    Box::pin(async move {
+                        use anyhow::Context;
+
+                        // Downcast the service reference to our concrete type
+                        let service = service_ref.downcast_ref::<#self_ty>()
+                            .ok_or_else(|| anyhow::anyhow!("Service type mismatch in subscription handler"))?;
+
+                        // Deliver the event payload to the original method
+                        service.#method_name(&context, payload).await
+                            .context(format!("Error handling subscription {}", #topic))
+                    })
+                }),
+            }
+        }
+    })
+}
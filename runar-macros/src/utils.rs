@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use syn::{Lit, LitStr, Meta};
+
+/// Extracts `key = "value"` pairs out of a parsed attribute `Meta` list.
+///
+/// Only `Meta::NameValue` entries whose value is a string literal are kept;
+/// anything else (bare paths, nested lists, non-string literals) is ignored
+/// here and left for the caller to reject explicitly if it cares.
+pub fn extract_name_value_pairs(metas: &[Meta]) -> HashMap<String, String> {
+    let mut pairs = HashMap::new();
+
+    for meta in metas {
+        if let Meta::NameValue(name_value) = meta {
+            if let Some(ident) = name_value.path.get_ident() {
+                if let Lit::Str(lit_str) = &name_value.lit {
+                    pairs.insert(ident.to_string(), lit_str.value());
+                }
+            }
+        }
+    }
+
+    pairs
+}
+
+/// Finds the first `key = "..."` entry and returns its string literal,
+/// preserving its span so callers can point a compile error at the
+/// offending attribute value rather than the whole `#[action(...)]`.
+pub fn find_name_value_lit<'a>(metas: &'a [Meta], key: &str) -> Option<&'a LitStr> {
+    find_name_value_lits(metas, key).into_iter().next()
+}
+
+/// Validates that every entry in `metas` is a `key = "string"` pair whose
+/// key is one of `known_keys`, rejecting anything else (bare paths like
+/// `#[action(admin)]`, nested lists, unknown keys such as a typo'd
+/// `nam = "x"`, or non-string values) with a spanned error pointing at the
+/// offending tokens. A bare literal such as `#[action("users")]` never parses
+/// into a `Meta`, so it is rejected by the caller's parse, not here.
+///
+/// `macro_name` (e.g. `"action"` or `"subscribe"`) is used only to phrase the
+/// error message for whichever attribute macro is calling this.
+///
+/// All problems found are combined into a single diagnostic, mirroring how
+/// actix-web-codegen reports malformed route attributes.
+pub fn validate_attribute_keys(
+    metas: &[Meta],
+    macro_name: &str,
+    known_keys: &[&str],
+) -> syn::Result<()> {
+    let expected = format!(
+        "invalid {macro_name} definition, expected #[{macro_name}({})]",
+        known_keys
+            .iter()
+            .map(|key| format!("{key} = \"...\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let mut error: Option<syn::Error> = None;
+    let mut push_error = |new: syn::Error| match &mut error {
+        Some(existing) => existing.combine(new),
+        None => error = Some(new),
+    };
+
+    for meta in metas {
+        match meta {
+            Meta::NameValue(name_value) => {
+                let ident = name_value.path.get_ident().map(|i| i.to_string());
+                let is_known = ident
+                    .as_deref()
+                    .is_some_and(|key| known_keys.contains(&key));
+                if !is_known {
+                    push_error(syn::Error::new_spanned(&name_value.path, &expected));
+                } else if !matches!(name_value.lit, Lit::Str(_)) {
+                    push_error(syn::Error::new_spanned(&name_value.lit, &expected));
+                }
+            }
+            other => push_error(syn::Error::new_spanned(other, &expected)),
+        }
+    }
+
+    match error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Like [`find_name_value_lit`], but returns every `key = "..."` entry in
+/// order instead of just the first - used for multi-valued keys such as
+/// `alias`, where `extract_name_value_pairs`'s map would silently collapse
+/// repeats down to one.
+pub fn find_name_value_lits<'a>(metas: &'a [Meta], key: &str) -> Vec<&'a LitStr> {
+    metas
+        .iter()
+        .filter_map(|meta| match meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident(key) => {
+                match &name_value.lit {
+                    Lit::Str(lit_str) => Some(lit_str),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_metas(attr: &str) -> Vec<Meta> {
+        let parser = syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated;
+        syn::parse::Parser::parse_str(parser, attr)
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn validate_attribute_keys_accepts_known_string_keys() {
+        let metas = parse_metas(r#"name = "get_user", alias = "fetch_user""#);
+        assert!(validate_attribute_keys(&metas, "action", &["name", "alias"]).is_ok());
+    }
+
+    #[test]
+    fn validate_attribute_keys_rejects_unknown_key() {
+        let metas = parse_metas(r#"nam = "get_user""#);
+        let err = validate_attribute_keys(&metas, "action", &["name"]).unwrap_err();
+        assert!(err.to_string().contains("invalid action definition"));
+    }
+
+    #[test]
+    fn validate_attribute_keys_rejects_bare_path() {
+        // A bare path meta like `#[action(admin)]` (no `= "..."` at all) is
+        // the "other" `Meta` shape `validate_attribute_keys` has to reject -
+        // unlike a bare string literal such as `#[action("users")]`, which
+        // can never parse into a `Meta` in the first place (the caller's own
+        // `Punctuated::<Meta, Token![,]>::parse_terminated` call rejects it
+        // before `validate_attribute_keys` is ever reached), so it isn't a
+        // case this function needs to - or can - cover.
+        let metas = parse_metas("admin");
+        let err = validate_attribute_keys(&metas, "action", &["name"]).unwrap_err();
+        assert!(err.to_string().contains("invalid action definition"));
+    }
+
+    #[test]
+    fn validate_attribute_keys_rejects_non_string_value() {
+        let metas = parse_metas("name = 1");
+        let err = validate_attribute_keys(&metas, "action", &["name"]).unwrap_err();
+        assert!(err.to_string().contains("invalid action definition"));
+    }
+
+    #[test]
+    fn validate_attribute_keys_uses_macro_name_in_message() {
+        let metas = parse_metas(r#"topc = "wrong""#);
+        let err = validate_attribute_keys(&metas, "subscribe", &["topic"]).unwrap_err();
+        assert!(err.to_string().contains("invalid subscribe definition"));
+    }
+
+    #[test]
+    fn find_name_value_lits_preserves_declaration_order() {
+        // The action macro runs each `guard` in this order before dispatch,
+        // so a repeated key must come back in declaration order, not
+        // collapsed or reordered the way `extract_name_value_pairs`'s map
+        // would.
+        let metas = parse_metas(
+            r#"name = "delete_user", guard = "require_admin", guard = "rate_limit""#,
+        );
+        let guards: Vec<String> = find_name_value_lits(&metas, "guard")
+            .into_iter()
+            .map(|lit| lit.value())
+            .collect();
+        assert_eq!(guards, vec!["require_admin", "rate_limit"]);
+    }
+}
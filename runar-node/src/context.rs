@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+/// Per-request context threaded through to every action and subscription handler.
+///
+/// Carries the metadata a handler needs about the call that isn't part of its
+/// typed parameters: the request id, who's calling, and any request-scoped
+/// key/value attributes set on it before it is handed to the handler (guards
+/// and handlers receive it as `&Context` and cannot change it).
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    pub request_id: String,
+    pub caller_id: Option<String>,
+    attributes: HashMap<String, String>,
+}
+
+impl Context {
+    pub fn new(request_id: impl Into<String>) -> Self {
+        Context {
+            request_id: request_id.into(),
+            caller_id: None,
+            attributes: HashMap::new(),
+        }
+    }
+
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(|s| s.as_str())
+    }
+
+    pub fn set_attribute(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.attributes.insert(key.into(), value.into());
+    }
+}
@@ -0,0 +1,21 @@
+//! Core runtime types for Runar services: the dynamic [`value::ValueType`],
+//! per-call [`context::Context`], the [`registry`] that `#[action]` and
+//! friends populate via `inventory`, and the [`service_response::ServiceResponse`]
+//! envelope returned from dispatch.
+//!
+//! Service crates that use the `#[action]` macro from `runar-macros` are
+//! expected to expose `registry` and `utils` at their own crate root (e.g.
+//! `pub use runar_node::{registry, utils};`), since the macro emits
+//! `crate::registry::...` / `crate::utils::...` paths relative to the crate
+//! the action is defined in.
+
+pub mod context;
+pub mod path_template;
+pub mod registry;
+pub mod service_response;
+pub mod utils;
+pub mod value;
+
+pub use context::Context;
+pub use service_response::ServiceResponse;
+pub use value::ValueType;
@@ -0,0 +1,158 @@
+use crate::value::{ParamsMap, ValueType};
+
+/// One segment of a compiled action path, as parsed by `#[action(path = "...")]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Literal(String),
+    Capture(String),
+    Wildcard(String),
+}
+
+/// A compiled `#[action(path = "...")]` pattern, stored on [`crate::registry::ActionItem`].
+///
+/// Parsing happens once, at macro-expansion time; this type just holds the
+/// already-split segments so dispatch only has to walk them against the
+/// incoming operation string.
+#[derive(Debug, Clone)]
+pub struct PathTemplate {
+    pub pattern: String,
+    pub segments: Vec<PathSegment>,
+}
+
+impl PathTemplate {
+    pub fn new(pattern: impl Into<String>, segments: Vec<PathSegment>) -> Self {
+        PathTemplate {
+            pattern: pattern.into(),
+            segments,
+        }
+    }
+
+    /// Matches `operation` against this template. On success, returns the
+    /// captured segment values merged into a params map (captures win over
+    /// any pre-existing entry of the same name).
+    pub fn matches(&self, operation: &str, params: &ParamsMap) -> Option<ParamsMap> {
+        let op_segments: Vec<&str> = operation.split('/').collect();
+        let mut captured = params.clone();
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Literal(literal) => {
+                    if op_segments.get(i) != Some(&literal.as_str()) {
+                        return None;
+                    }
+                }
+                PathSegment::Capture(name) => {
+                    let value = op_segments.get(i)?;
+                    captured.insert(name.clone(), ValueType::from(value.to_string()));
+                }
+                PathSegment::Wildcard(name) => {
+                    let rest = op_segments.get(i..)?.join("/");
+                    captured.insert(name.clone(), ValueType::from(rest));
+                    return Some(captured);
+                }
+            }
+        }
+
+        if op_segments.len() != self.segments.len() {
+            return None;
+        }
+
+        Some(captured)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::IntoValue;
+
+    fn empty_params() -> ParamsMap {
+        ParamsMap::new()
+    }
+
+    #[test]
+    fn matches_literal_and_captures_dynamic_segments() {
+        let template = PathTemplate::new(
+            "users/{id}/posts/{post_id}",
+            vec![
+                PathSegment::Literal("users".to_string()),
+                PathSegment::Capture("id".to_string()),
+                PathSegment::Literal("posts".to_string()),
+                PathSegment::Capture("post_id".to_string()),
+            ],
+        );
+
+        let captured = template
+            .matches("users/42/posts/7", &empty_params())
+            .unwrap();
+
+        assert_eq!(captured.get("id").unwrap().0, serde_json::json!("42"));
+        assert_eq!(captured.get("post_id").unwrap().0, serde_json::json!("7"));
+    }
+
+    #[test]
+    fn rejects_mismatched_literal() {
+        let template = PathTemplate::new(
+            "users/{id}",
+            vec![
+                PathSegment::Literal("users".to_string()),
+                PathSegment::Capture("id".to_string()),
+            ],
+        );
+
+        assert!(template.matches("groups/42", &empty_params()).is_none());
+    }
+
+    #[test]
+    fn rejects_wrong_segment_count() {
+        let template = PathTemplate::new(
+            "users/{id}",
+            vec![
+                PathSegment::Literal("users".to_string()),
+                PathSegment::Capture("id".to_string()),
+            ],
+        );
+
+        assert!(template.matches("users/42/extra", &empty_params()).is_none());
+        assert!(template.matches("users", &empty_params()).is_none());
+    }
+
+    #[test]
+    fn trailing_wildcard_captures_the_remainder() {
+        let template = PathTemplate::new(
+            "files/{rest:*}",
+            vec![
+                PathSegment::Literal("files".to_string()),
+                PathSegment::Wildcard("rest".to_string()),
+            ],
+        );
+
+        let captured = template
+            .matches("files/a/b/c", &empty_params())
+            .unwrap();
+
+        assert_eq!(captured.get("rest").unwrap().0, serde_json::json!("a/b/c"));
+    }
+
+    #[test]
+    fn captures_are_merged_with_existing_params() {
+        let template = PathTemplate::new(
+            "users/{id}",
+            vec![
+                PathSegment::Literal("users".to_string()),
+                PathSegment::Capture("id".to_string()),
+            ],
+        );
+
+        let mut params = empty_params();
+        params.insert("include_posts".to_string(), true.into_value());
+
+        let captured = template.matches("users/42", &params).unwrap();
+
+        assert_eq!(captured.get("id").unwrap().0, serde_json::json!("42"));
+        assert_eq!(
+            captured.get("include_posts").unwrap().0,
+            serde_json::json!(true)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::context::Context;
+use crate::path_template::PathTemplate;
+use crate::service_response::ServiceResponse;
+use crate::value::{ParamsMap, ValueType};
+use anyhow::Result;
+use std::any::{Any, TypeId};
+use std::future::Future;
+use std::pin::Pin;
+
+type HandlerFuture = Pin<Box<dyn Future<Output = Result<ServiceResponse>> + Send>>;
+type SubscriptionFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+
+/// A single registered action, discovered at startup via `inventory`.
+///
+/// `#[action]` emits one of these per operation name of a handler (its
+/// `name` plus each `alias`) so the node can dispatch an operation name to the right service
+/// method without either side knowing about the other at compile time.
+pub struct ActionItem {
+    pub name: String,
+    pub service_type_id: TypeId,
+    /// Set when the handler was declared with `#[action(path = "...")]`;
+    /// lets `find_action` match a request against `users/{id}` style names
+    /// in addition to the flat `name` above.
+    pub path_template: Option<PathTemplate>,
+    pub handler_fn:
+        Box<dyn Fn(&dyn Any, Context, String, ParamsMap) -> HandlerFuture + Send + Sync>,
+}
+
+inventory::collect!(ActionItem);
+
+/// Resolves an operation string to its registered action.
+///
+/// Exact `name` matches win; otherwise every registered `path_template` is
+/// tried in `inventory`'s iteration order, which is unspecified, and the
+/// first match is used. On a path match, the captured segments are
+/// merged into the returned params map under their capture names.
+pub fn find_action(operation: &str, params: &ParamsMap) -> Option<(&'static ActionItem, ParamsMap)> {
+    for item in inventory::iter::<ActionItem> {
+        if item.name == operation {
+            return Some((item, params.clone()));
+        }
+    }
+
+    for item in inventory::iter::<ActionItem> {
+        if let Some(template) = &item.path_template {
+            if let Some(merged) = template.matches(operation, params) {
+                return Some((item, merged));
+            }
+        }
+    }
+
+    None
+}
+
+/// A single registered event handler, discovered at startup via `inventory`.
+///
+/// `#[subscribe]` emits one of these per handler so the node's event bus can
+/// deliver a published event to every service listening on its topic
+/// without either side knowing about the other at compile time.
+pub struct SubscriptionItem {
+    pub topic: String,
+    pub service_type_id: TypeId,
+    pub handler_fn:
+        Box<dyn Fn(&dyn Any, Context, ValueType) -> SubscriptionFuture + Send + Sync>,
+}
+
+inventory::collect!(SubscriptionItem);
+
+/// Returns every subscription registered for `topic`.
+pub fn subscriptions_for(topic: &str) -> impl Iterator<Item = &'static SubscriptionItem> + '_ {
+    inventory::iter::<SubscriptionItem>
+        .into_iter()
+        .filter(move |item| item.topic == topic)
+}
@@ -0,0 +1,27 @@
+use crate::value::ValueType;
+
+/// Uniform envelope returned from every action dispatch.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ServiceResponse {
+    pub success: bool,
+    pub message: String,
+    pub data: Option<ValueType>,
+}
+
+impl ServiceResponse {
+    pub fn success(message: impl Into<String>, data: Option<ValueType>) -> Self {
+        ServiceResponse {
+            success: true,
+            message: message.into(),
+            data,
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        ServiceResponse {
+            success: false,
+            message: message.into(),
+            data: None,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::value::ValueType;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+/// Pulls a single named, typed parameter out of an action's params map.
+///
+/// This is the runtime counterpart to the extraction code the `#[action]`
+/// macro generates: for every non-`self`/non-context argument in a handler's
+/// signature, the macro emits one call to this function before invoking the
+/// original method.
+pub fn extract_parameter<T, P>(params: &P, name: &str, error_msg: &str) -> anyhow::Result<T>
+where
+    T: DeserializeOwned,
+    P: ParamsLookup,
+{
+    let value = params
+        .lookup(name)
+        .ok_or_else(|| anyhow::anyhow!(error_msg.to_string()))?;
+
+    value
+        .clone()
+        .into_typed()
+        .map_err(|e| anyhow::anyhow!("Invalid value for parameter '{}': {}", name, e))
+}
+
+/// Implemented by the shapes an action's `params` argument can take.
+pub trait ParamsLookup {
+    fn lookup(&self, name: &str) -> Option<&ValueType>;
+}
+
+impl ParamsLookup for HashMap<String, ValueType> {
+    fn lookup(&self, name: &str) -> Option<&ValueType> {
+        self.get(name)
+    }
+}
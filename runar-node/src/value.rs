@@ -0,0 +1,53 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Dynamic value exchanged between the node dispatcher and service handlers.
+///
+/// `ValueType` is the wire representation used for both action parameters and
+/// action results. It is intentionally a thin wrapper around a JSON-shaped
+/// value so that any `Serialize`/`Deserialize` type can cross the boundary
+/// without the framework needing to know about it ahead of time.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ValueType(pub serde_json::Value);
+
+impl ValueType {
+    pub fn null() -> Self {
+        ValueType(serde_json::Value::Null)
+    }
+
+    /// Looks up a named entry, assuming this value is a map (e.g. action params).
+    pub fn get(&self, name: &str) -> Option<&serde_json::Value> {
+        self.0.get(name)
+    }
+
+    /// Deserializes this value into a concrete type.
+    pub fn into_typed<T: DeserializeOwned>(self) -> Result<T, serde_json::Error> {
+        serde_json::from_value(self.0)
+    }
+}
+
+/// Converts an action's native return type into the wire `ValueType`.
+///
+/// Implemented for anything `Serialize` via `IntoValue::into_value()` rather
+/// than `std::convert::Into` so it doesn't collide with the blanket identity
+/// conversion and so action methods never need to derive anything extra.
+pub trait IntoValue {
+    fn into_value(self) -> ValueType;
+}
+
+impl<T: Serialize> IntoValue for T {
+    fn into_value(self) -> ValueType {
+        ValueType(serde_json::to_value(self).unwrap_or(serde_json::Value::Null))
+    }
+}
+
+impl From<String> for ValueType {
+    fn from(value: String) -> Self {
+        ValueType(serde_json::Value::String(value))
+    }
+}
+
+/// Convenience alias for the params map handed to action handlers.
+pub type ParamsMap = HashMap<String, ValueType>;